@@ -172,7 +172,10 @@ impl Chain {
     ///
     /// # Returns
     ///
-    /// * `bool` - Always returns `true` indicating the block was generated and added successfully.
+    /// * `bool` - `true` if the block was mined and appended to the chain; `false` if
+    ///   `proof_of_work` exhausted the nonce space before meeting the difficulty target,
+    ///   in which case the block is discarded and its transactions are returned to the
+    ///   pending queue.
     ///
     /// # Examples
     ///
@@ -208,7 +211,12 @@ impl Chain {
         block.count = block.transactions.len() as u32;
         block.header.merkle = Chain::get_merkle(block.transactions.clone());
 
-        Chain::proof_of_work(&mut block.header);
+        if Chain::proof_of_work(&mut block.header).is_err() {
+            println!("Proof of work failed: nonce exhausted before reaching the target.");
+            block.transactions.remove(0); // drop the reward transaction
+            self.curr_trans = block.transactions;
+            return false;
+        }
 
         println!("{:#?}", &block);
         self.chain.push(block);
@@ -261,13 +269,20 @@ impl Chain {
 
     /// Performs proof-of-work to find a valid hash for the block header.
     ///
-    /// The function iteratively increments the nonce and computes the hash of the header
-    /// until the hash meets the difficulty target (starts with a specific number of leading zeros).
+    /// The function iteratively increments the nonce and computes the hash of the header,
+    /// treating the 32-byte SHA-256 digest as a big-endian 256-bit unsigned integer, until
+    /// that integer is less than or equal to the difficulty target (`2^(256 - difficulty)`).
+    /// Each extra difficulty unit halves the space of acceptable hashes.
     ///
     /// # Arguments
     ///
     /// * `header` - A mutable reference to the `Blockheader` that needs proof-of-work.
     ///
+    /// # Returns
+    ///
+    /// * `Result<(), String>` - `Ok` once a valid nonce is found, or `Err` if `nonce`
+    ///   overflows `u32` before a hash meeting the target is found.
+    ///
     /// # Examples
     ///
     /// ```
@@ -278,31 +293,54 @@ impl Chain {
     ///     merkle: String::new(),
     ///     difficulty: 4,
     /// };
-    /// Chain::proof_of_work(&mut header);
+    /// Chain::proof_of_work(&mut header).expect("nonce space exhausted");
     /// println!("Nonce: {}, Hash: {}", header.nonce, Chain::hash(&header));
     /// ```
-    pub fn proof_of_work(header: &mut Blockheader) {
+    pub fn proof_of_work(header: &mut Blockheader) -> Result<(), String> {
+        let target = Chain::target(header.difficulty);
+
         loop {
-            let hash = Chain::hash(header);
-            let slice = &hash[..header.difficulty as usize];
-
-            match slice.parse::<u32>() {
-                Ok(val) => {
-                    if val != 0 {
-                        header.nonce += 1;
-                    } else {
-                        println!("Block hash: {}", hash);
-                        break;
-                    }
-                }
-                Err(_) => {
-                    header.nonce += 1;
-                    continue;
-                }
+            let digest = Chain::hash_bytes(header);
+
+            if digest <= target {
+                println!("Block hash: {}", Chain::hex_to_string(&digest));
+                return Ok(());
+            }
+
+            header.nonce = match header.nonce.checked_add(1) {
+                Some(nonce) => nonce,
+                None => return Err(String::from("nonce overflowed before reaching the target")),
             };
         }
     }
 
+    /// Computes the proof-of-work target for a given difficulty, as a big-endian 256-bit
+    /// unsigned integer: `2^(256 - difficulty)`.
+    ///
+    /// A `difficulty` of `0` would require a target of `2^256`, which does not fit in 256
+    /// bits, so it is treated as "accept any hash" and represented by the maximum 256-bit
+    /// value. `difficulty` values above `256` are clamped to `256` (target of `1`).
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - A `u32` that sets the mining difficulty.
+    ///
+    /// # Returns
+    ///
+    /// * `[u8; 32]` - The target, as 32 big-endian bytes.
+    fn target(difficulty: u32) -> [u8; 32] {
+        if difficulty == 0 {
+            return [0xff; 32];
+        }
+
+        let shift = 256 - difficulty.min(256);
+        let mut target = [0u8; 32];
+        let byte_from_msb = 31 - (shift / 8) as usize;
+        target[byte_from_msb] = 1u8 << (shift % 8);
+
+        target
+    }
+
     /// Computes the SHA-256 hash of a serializable item.
     ///
     /// # Arguments
@@ -328,13 +366,24 @@ impl Chain {
     /// println!("Hash: {}", hash);
     /// ```
     pub fn hash<T: serde::Serialize>(item: &T) -> String {
+        Chain::hex_to_string(&Chain::hash_bytes(item))
+    }
+
+    /// Computes the raw SHA-256 digest of a serializable item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - A reference to an item that implements the `serde::Serialize` trait.
+    ///
+    /// # Returns
+    ///
+    /// * `[u8; 32]` - The 32-byte SHA-256 digest of the item.
+    fn hash_bytes<T: serde::Serialize>(item: &T) -> [u8; 32] {
         let input = serde_json::to_string(&item).unwrap();
         let mut hasher = Sha256::new();
         hasher.update(input.as_bytes());
-        let res = hasher.finalize();
-        let vec_res = res.to_vec();
 
-        Chain::hex_to_string(vec_res.as_slice())
+        hasher.finalize().into()
     }
 
     /// Converts a slice of bytes into a hexadecimal string representation.
@@ -357,7 +406,7 @@ impl Chain {
     pub fn hex_to_string(vec_res: &[u8]) -> String {
         let mut s = String::new();
         for b in vec_res {
-            write!(&mut s, "{:x}", b).expect("unable to write");
+            write!(&mut s, "{:02x}", b).expect("unable to write");
         }
 
         s
@@ -470,7 +519,7 @@ mod tests {
 
         let hash = chain.last_hash();
 
-        assert_eq!(hash.len(), 64 - 1); // Assuming hash length for non-empty chain, subtract 1 due to left padding
+        assert_eq!(hash.len(), 64);
     }
 
     #[test]
@@ -483,7 +532,7 @@ mod tests {
 
         let hash = Chain::hash(&transaction);
 
-        assert_eq!(hash.len(), 64 - 3); // Assuming hash length for SHA-256, subtract 3 due to left padding
+        assert_eq!(hash.len(), 64);
     }
 
     #[test]
@@ -495,6 +544,15 @@ mod tests {
         assert_eq!(hex_string, "48656c6c6f");
     }
 
+    #[test]
+    fn test_chain_hex_to_string_zero_padded() {
+        let bytes = vec![0x00, 0x0a, 0xff];
+
+        let hex_string = Chain::hex_to_string(&bytes);
+
+        assert_eq!(hex_string, "000aff");
+    }
+
     #[test]
     fn test_chain_get_merkle() {
         let transactions = vec![
@@ -512,6 +570,46 @@ mod tests {
 
         let merkle_root = Chain::get_merkle(transactions);
 
-        assert_eq!(merkle_root.len(), 64 - 6); // Assuming hash length for merkle root, subtract 6 due to left padding
+        assert_eq!(merkle_root.len(), 64);
+    }
+
+    #[test]
+    fn test_target_zero_difficulty_accepts_max_hash() {
+        assert_eq!(Chain::target(0), [0xff; 32]);
+    }
+
+    #[test]
+    fn test_target_full_difficulty_is_one() {
+        let mut expected = [0u8; 32];
+        expected[31] = 1;
+
+        assert_eq!(Chain::target(256), expected);
+    }
+
+    #[test]
+    fn test_target_halves_per_difficulty_unit() {
+        let mut expected = [0u8; 32];
+        expected[0] = 0b1000_0000;
+        assert_eq!(Chain::target(1), expected);
+
+        let mut expected = [0u8; 32];
+        expected[0] = 0b0100_0000;
+        assert_eq!(Chain::target(2), expected);
+    }
+
+    #[test]
+    fn test_proof_of_work_meets_difficulty() {
+        let mut header = Blockheader {
+            timestamp: 0,
+            nonce: 0,
+            pre_hash: String::new(),
+            merkle: String::new(),
+            difficulty: 2,
+        };
+
+        Chain::proof_of_work(&mut header).unwrap();
+
+        let digest = Chain::hash_bytes(&header);
+        assert!(digest <= Chain::target(header.difficulty));
     }
 }